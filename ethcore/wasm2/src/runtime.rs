@@ -1,5 +1,5 @@
 use ethereum_types::{U256, H256, Address};
-use vm;
+use vm::{self, CallType};
 use wasmi::{self, MemoryRef, RuntimeArgs, RuntimeValue, Error as InterpreterError};
 
 pub struct RuntimeContext {
@@ -10,6 +10,69 @@ pub struct RuntimeContext {
 	pub value: U256,
 }
 
+/// Per-instruction-class multipliers applied to the gas reported by the
+/// instrumentation pass the wasm bytecode is metered with. All costs
+/// default to `1`, preserving the previous flat accounting.
+#[derive(Debug, Clone)]
+pub struct WasmCosts {
+	/// Gas cost of one byte allocated via the `alloc` syscall
+	pub alloc: u32,
+	/// Multiplier for metered division instructions
+	pub div: u32,
+	/// Multiplier for metered multiplication instructions
+	pub mul: u32,
+	/// Multiplier for metered memory load/store instructions
+	pub mem: u32,
+	/// Gas cost of one byte copied by a metered memory-copy instruction
+	pub mem_copy: u32,
+	/// Gas cost of one byte of static region declared by the module
+	pub static_region: u32,
+}
+
+impl Default for WasmCosts {
+	fn default() -> Self {
+		WasmCosts {
+			alloc: 1,
+			div: 1,
+			mul: 1,
+			mem: 1,
+			mem_copy: 1,
+			static_region: 1,
+		}
+	}
+}
+
+/// Class of a metered instruction, passed alongside the raw instruction
+/// count so `gas` can look up the configured multiplier in `WasmCosts`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GasClass {
+	/// Unclassified instruction, charged at the reported count
+	Base,
+	/// Division instruction
+	Div,
+	/// Multiplication instruction
+	Mul,
+	/// Memory load/store instruction
+	Mem,
+	/// Memory-copy instruction, priced per byte copied
+	MemCopy,
+	/// Static region declared by the module, priced per byte
+	StaticRegion,
+}
+
+impl From<u32> for GasClass {
+	fn from(tag: u32) -> Self {
+		match tag {
+			1 => GasClass::Div,
+			2 => GasClass::Mul,
+			3 => GasClass::Mem,
+			4 => GasClass::MemCopy,
+			5 => GasClass::StaticRegion,
+			_ => GasClass::Base,
+		}
+	}
+}
+
 pub struct Runtime<'a> {
 	gas_counter: u64,
 	gas_limit: u64,
@@ -18,6 +81,9 @@ pub struct Runtime<'a> {
 	memory: MemoryRef,
 	args: Vec<u8>,
 	result: Vec<u8>,
+	// Top of the dynamically-allocated region of linear memory, bumped by
+	// the `alloc` syscall and used to grow the wasm memory on demand.
+	dynamic_top: u32,
 }
 
 /// User trap in native code
@@ -91,6 +157,57 @@ impl ::std::fmt::Display for Error {
 
 type Result<T> = ::std::result::Result<T, Error>;
 
+/// A region of wasm linear memory that has already been validated against
+/// the memory's current size, so reading it cannot trigger an
+/// attacker-controlled out-of-bounds access or oversized allocation.
+#[derive(Debug, Clone, Copy)]
+struct WasmPtr {
+	offset: u32,
+	len: u32,
+}
+
+/// Why a candidate `(offset, len)` pair failed to become a `WasmPtr`
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum WasmPtrError {
+	/// `offset` itself lies outside of the allocated linear memory
+	OutOfBoundsOffset,
+	/// The requested length is zero
+	ZeroLength,
+	/// `offset + len` overflows or exceeds the allocated linear memory
+	OversizedLength,
+}
+
+impl From<WasmPtrError> for Error {
+	fn from(_: WasmPtrError) -> Self {
+		Error::MemoryAccessViolation
+	}
+}
+
+impl WasmPtr {
+	/// Validate `offset`/`len` against `memory`'s current size
+	fn new(memory: &MemoryRef, offset: u32, len: u32) -> ::std::result::Result<WasmPtr, WasmPtrError> {
+		if len == 0 {
+			return Err(WasmPtrError::ZeroLength);
+		}
+
+		// `current_size()` is in units of 64KiB pages and can reach 65536
+		// pages (4GiB) for a wasm32 memory, which overflows `u32` if
+		// multiplied directly — compute the byte size in `u64`.
+		let memory_size = memory.current_size().0 as u64 * 64 * 1024;
+		let offset = offset as u64;
+		let len = len as u64;
+
+		if offset >= memory_size {
+			return Err(WasmPtrError::OutOfBoundsOffset);
+		}
+
+		match offset.checked_add(len) {
+			Some(end) if end <= memory_size => Ok(WasmPtr { offset: offset as u32, len: len as u32 }),
+			_ => Err(WasmPtrError::OversizedLength),
+		}
+	}
+}
+
 impl<'a> Runtime<'a> {
 	/// New runtime for wasm contract with specified params
 	pub fn with_params(
@@ -108,16 +225,32 @@ impl<'a> Runtime<'a> {
 			context: context,
 			args: args,
 			result: Vec::new(),
+			dynamic_top: 0,
 		}
 	}
 
 	fn h256_at(&self, ptr: u32) -> Result<H256> {
+		let region = WasmPtr::new(&self.memory, ptr, 32)?;
+
 		let mut buf = [0u8; 32];
-		self.memory.get_into(ptr, &mut buf[..])?;
+		self.memory.get_into(region.offset, &mut buf[..])?;
 
 		Ok(H256::from(&buf[..]))
 	}
 
+	fn address_at(&self, ptr: u32) -> Result<Address> {
+		let region = WasmPtr::new(&self.memory, ptr, 20)?;
+
+		let mut buf = [0u8; 20];
+		self.memory.get_into(region.offset, &mut buf[..])?;
+
+		Ok(Address::from(&buf[..]))
+	}
+
+	fn u256_at(&self, ptr: u32) -> Result<U256> {
+		Ok(U256::from(self.h256_at(ptr)?))
+	}
+
 	fn charge_gas(&mut self, amount: u64) -> bool {
 		let prev = self.gas_counter;
 		if prev + amount > self.gas_limit {
@@ -151,7 +284,29 @@ impl<'a> Runtime<'a> {
 
 		self.charge(|schedule| schedule.sload_gas as u64)?;
 
-		self.memory.set(val_ptr as u32, &*val)?;
+		let region = WasmPtr::new(&self.memory, val_ptr, 32)?;
+		self.memory.set(region.offset, &*val)?;
+
+		Ok(())
+	}
+
+	/// Write to the storage from wasm memory
+	pub fn storage_write(&mut self, args: RuntimeArgs) -> Result<()> {
+		let key = self.h256_at(args.nth(0)?)?;
+		let val_ptr: u32 = args.nth(1)?;
+		let value = self.h256_at(val_ptr)?;
+
+		let old_value = self.ext.storage_at(&key).map_err(|_| Error::StorageUpdateError)?;
+
+		// EVM-style SSTORE pricing: writing a previously-zero slot to a
+		// non-zero value is more expensive than any other transition.
+		if old_value.is_zero() && !value.is_zero() {
+			self.charge(|schedule| schedule.sstore_set_gas as u64)?;
+		} else {
+			self.charge(|schedule| schedule.sstore_reset_gas as u64)?;
+		}
+
+		self.ext.set_storage(key, value).map_err(|_| Error::StorageUpdateError)?;
 
 		Ok(())
 	}
@@ -164,7 +319,12 @@ impl<'a> Runtime<'a> {
 		let ptr: u32 = args.nth(0)?;
 		let len: u32 = args.nth(1)?;
 
-		self.result = self.memory.get(ptr, len as usize)?;
+		self.result = if len == 0 {
+			Vec::new()
+		} else {
+			let region = WasmPtr::new(&self.memory, ptr, len)?;
+			self.memory.get(region.offset, region.len as usize)?
+		};
 
 		Ok(())
 	}
@@ -177,6 +337,232 @@ impl<'a> Runtime<'a> {
 		self.result
 	}
 
+	/// Query the balance of the given address
+	pub fn balance(&mut self, args: RuntimeArgs) -> Result<()> {
+		let address = self.address_at(args.nth(0)?)?;
+		let balance_ptr: u32 = args.nth(1)?;
+
+		let balance = self.ext.balance(&address).map_err(|_| Error::BalanceQueryError)?;
+
+		let region = WasmPtr::new(&self.memory, balance_ptr, 32)?;
+		self.memory.set(region.offset, &*H256::from(balance))?;
+
+		Ok(())
+	}
+
+	/// Regular message-call into another contract, forwarding `value`
+	pub fn ccall(&mut self, args: RuntimeArgs) -> Result<RuntimeValue> {
+		self.do_call(true, CallType::Call, args)
+	}
+
+	/// Delegate-call into another contract's code, keeping this contract's
+	/// sender, address and value
+	pub fn dcall(&mut self, args: RuntimeArgs) -> Result<RuntimeValue> {
+		self.do_call(false, CallType::DelegateCall, args)
+	}
+
+	/// Static call into another contract, disallowing any state mutation
+	pub fn scall(&mut self, args: RuntimeArgs) -> Result<RuntimeValue> {
+		self.do_call(false, CallType::StaticCall, args)
+	}
+
+	fn do_call(
+		&mut self,
+		use_val: bool,
+		call_type: CallType,
+		args: RuntimeArgs,
+	) -> Result<RuntimeValue> {
+		trace!(target: "wasm", "runtime: CALL({:?})", call_type);
+
+		let gas: i64 = args.nth(0)?;
+		let address = self.address_at(args.nth(1)?)?;
+
+		let val = if use_val { Some(self.u256_at(args.nth(2)?)?) } else { None };
+		let base = if use_val { 3 } else { 2 };
+
+		let input_ptr: u32 = args.nth(base)?;
+		let input_len: u32 = args.nth(base + 1)?;
+		let result_ptr: u32 = args.nth(base + 2)?;
+		let result_len: u32 = args.nth(base + 3)?;
+
+		self.charge(|schedule| schedule.call_gas as u64)?;
+
+		let gas_left = self.gas_left()?;
+		let forwarded_gas = ::std::cmp::min(gas.max(0) as u64, gas_left);
+
+		let input = if input_len == 0 {
+			Vec::new()
+		} else {
+			let input_region = WasmPtr::new(&self.memory, input_ptr, input_len)?;
+			self.memory.get(input_region.offset, input_region.len as usize)?
+		};
+
+		let (sender, receiver) = match call_type {
+			CallType::DelegateCall => (self.context.sender, self.context.address),
+			_ => (self.context.address, address),
+		};
+
+		let result = self.ext.call(
+			&U256::from(forwarded_gas),
+			&sender,
+			&receiver,
+			val,
+			&input,
+			&address,
+			call_type,
+		);
+
+		// Charge only the gas the callee actually consumed, not the whole
+		// forwarded allowance (mirrors `create`'s gas accounting below).
+		let (status, data) = match result {
+			vm::MessageCallResult::Success(returned_gas_left, data) => {
+				self.charge(|_| gas_consumed(forwarded_gas, returned_gas_left))?;
+				(0, data)
+			},
+			vm::MessageCallResult::Reverted(returned_gas_left, data) => {
+				self.charge(|_| gas_consumed(forwarded_gas, returned_gas_left))?;
+				(1, data)
+			},
+			vm::MessageCallResult::Failed => {
+				self.charge(|_| forwarded_gas)?;
+				(1, vm::ReturnData::empty())
+			},
+		};
+
+		let copy_len = ::std::cmp::min(result_len as usize, data.len());
+		if copy_len > 0 {
+			let result_region = WasmPtr::new(&self.memory, result_ptr, copy_len as u32)?;
+			self.memory.set(result_region.offset, &data[..copy_len])?;
+		}
+
+		Ok(RuntimeValue::I32(status))
+	}
+
+	/// Create a new contract, writing its address to the 20 bytes at
+	/// `result_ptr`; returns `0` on success and `1` on failure or revert
+	pub fn create(&mut self, args: RuntimeArgs) -> Result<RuntimeValue> {
+		let endowment = self.u256_at(args.nth(0)?)?;
+		let code_ptr: u32 = args.nth(1)?;
+		let code_len: u32 = args.nth(2)?;
+		let result_ptr: u32 = args.nth(3)?;
+
+		self.charge(|schedule| schedule.create_gas as u64)?;
+
+		let code_region = WasmPtr::new(&self.memory, code_ptr, code_len)?;
+		let code = self.memory.get(code_region.offset, code_region.len as usize)?;
+		let gas_left = self.gas_left()?;
+
+		// Charge for whatever the created contract actually consumed, not
+		// just the flat `create_gas` — otherwise execution inside the new
+		// contract is untracked by the caller's gas meter.
+		let result = self.ext.create(&U256::from(gas_left), &endowment, &code, vm::CreateContractAddress::FromSenderAndNonce);
+
+		match result {
+			vm::ContractCreateResult::Created(address, returned_gas_left) => {
+				self.charge(|_| gas_consumed(gas_left, returned_gas_left))?;
+				let result_region = WasmPtr::new(&self.memory, result_ptr, 20)?;
+				self.memory.set(result_region.offset, &*address)?;
+				Ok(RuntimeValue::I32(0))
+			},
+			vm::ContractCreateResult::Failed => {
+				self.charge(|_| gas_left)?;
+				Ok(RuntimeValue::I32(1))
+			},
+			vm::ContractCreateResult::Reverted(returned_gas_left, _data) => {
+				self.charge(|_| gas_consumed(gas_left, returned_gas_left))?;
+				Ok(RuntimeValue::I32(1))
+			},
+		}
+	}
+
+	/// Destroy the contract, sending any remaining balance to `refund_address`
+	pub fn suicide(&mut self, args: RuntimeArgs) -> Result<()> {
+		let refund_address = self.address_at(args.nth(0)?)?;
+
+		self.ext.suicide(&refund_address).map_err(|_| Error::SuicideAbort)?;
+
+		// Returned as an error so the interpreter unwinds immediately; the
+		// caller maps this back to a clean stop rather than a real fault.
+		Err(Error::Suicide)
+	}
+
+	/// Decode a structured panic payload and abort with `Error::Panic`
+	///
+	/// Payload layout: a 1-byte "has message" flag followed, if set, by a
+	/// little-endian u32 length and that many UTF-8 message bytes; then a
+	/// 1-byte "has location" flag followed, if set, by a u32 length + file
+	/// name bytes and a little-endian u32 line number.
+	pub fn panic(&mut self, args: RuntimeArgs) -> Result<()> {
+		let payload_ptr: u32 = args.nth(0)?;
+		let payload_len: u32 = args.nth(1)?;
+
+		let region = WasmPtr::new(&self.memory, payload_ptr, payload_len)?;
+		let payload = self.memory.get(region.offset, region.len as usize)?;
+		let mut cursor = 0usize;
+
+		let message = if read_flag(&payload, &mut cursor)? {
+			let len = read_u32(&payload, &mut cursor)? as usize;
+			let bytes = read_bytes(&payload, &mut cursor, len)?;
+			Some(String::from_utf8(bytes.to_vec()).map_err(|_| Error::BadUtf8)?)
+		} else {
+			None
+		};
+
+		let location = if read_flag(&payload, &mut cursor)? {
+			let len = read_u32(&payload, &mut cursor)? as usize;
+			let file_bytes = read_bytes(&payload, &mut cursor, len)?;
+			let file = String::from_utf8(file_bytes.to_vec()).map_err(|_| Error::BadUtf8)?;
+			let line = read_u32(&payload, &mut cursor)?;
+			Some((file, line))
+		} else {
+			None
+		};
+
+		let formatted = match (message, location) {
+			(Some(msg), Some((file, line))) => format!("{}, {}:{}", msg, file, line),
+			(Some(msg), None) => msg,
+			(None, Some((file, line))) => format!("{}:{}", file, line),
+			(None, None) => String::new(),
+		};
+
+		Err(Error::Panic(formatted))
+	}
+
+	/// Signal an event to an external listener
+	pub fn elog(&mut self, args: RuntimeArgs) -> Result<()> {
+		let topics_ptr: u32 = args.nth(0)?;
+		let topics_count: u32 = args.nth(1)?;
+		let data_ptr: u32 = args.nth(2)?;
+		let data_len: u32 = args.nth(3)?;
+
+		if topics_count > 4 {
+			return Err(Error::Log);
+		}
+
+		let mut topics = Vec::with_capacity(topics_count as usize);
+		for i in 0..topics_count {
+			let topic_ptr = topics_ptr.checked_add(i * 32).ok_or(Error::MemoryAccessViolation)?;
+			topics.push(self.h256_at(topic_ptr)?);
+		}
+
+		let data = if data_len == 0 {
+			Vec::new()
+		} else {
+			let region = WasmPtr::new(&self.memory, data_ptr, data_len)?;
+			self.memory.get(region.offset, region.len as usize)?
+		};
+
+		self.charge(|schedule| {
+			schedule.log_gas as u64
+				+ schedule.log_topic_gas as u64 * topics_count as u64
+				+ schedule.log_data_gas as u64 * data_len as u64
+		})?;
+
+		self.ext.log(topics, &data).map_err(|_| Error::Log)?;
+
+		Ok(())
+	}
+
 	/// Query current gas left for execution
 	pub fn gas_left(&self) -> Result<u64> {
 		if self.gas_counter > self.gas_limit { return Err(Error::InvalidGasState); }
@@ -184,15 +570,146 @@ impl<'a> Runtime<'a> {
 	}
 
 	/// Report gas cost with the params passed in wasm stack
+	///
+	/// The second argument is a `GasClass` tag produced by the metering
+	/// instrumentation; the reported instruction count is multiplied by the
+	/// configured `WasmCosts` cost for that class before being charged. For
+	/// `MemCopy` and `StaticRegion`, the "instruction count" the
+	/// instrumentation reports is actually a byte count (bytes copied, or
+	/// bytes of static data declared by the module), so the multiplier
+	/// still applies per unit reported.
 	fn gas(&mut self, args: RuntimeArgs) -> Result<()> {
-		trace!(target: "wasm", "charge gas {}", args.nth::<u32>(0)?);
 		let amount: u32 = args.nth(0)?;
-		if self.charge_gas(amount as u64) {
+		let class: GasClass = args.nth::<u32>(1)?.into();
+		trace!(target: "wasm", "charge gas {} ({:?})", amount, class);
+
+		let multiplier = match class {
+			GasClass::Base => 1,
+			GasClass::Div => self.schedule().wasm.div,
+			GasClass::Mul => self.schedule().wasm.mul,
+			GasClass::Mem => self.schedule().wasm.mem,
+			GasClass::MemCopy => self.schedule().wasm.mem_copy,
+			GasClass::StaticRegion => self.schedule().wasm.static_region,
+		};
+
+		if self.charge_gas(amount as u64 * multiplier as u64) {
 			Ok(())
 		} else {
 			Err(Error::GasLimit.into())
 		}
 	}
+
+	/// Reserve `amount` bytes of linear memory from a bump pointer that starts
+	/// at offset `0`, growing the wasm memory when the new top exceeds its
+	/// current size, and return the offset of the newly reserved region.
+	/// Charges `wasm.alloc` gas per byte, so running out of gas is
+	/// detected at allocation time rather than on first use.
+	fn alloc(&mut self, args: RuntimeArgs) -> Result<u32> {
+		let amount: u32 = args.nth(0)?;
+
+		self.charge(|schedule| schedule.wasm.alloc as u64 * amount as u64)?;
+
+		let previous_top = self.dynamic_top;
+		let new_top = previous_top.checked_add(amount).ok_or(Error::AllocationFailed)?;
+
+		let current_pages = self.memory.current_size().0 as u64;
+		// `new_top` can reach `u32::MAX`, so widen to `u64` before adding the
+		// rounding term (mirrors the overflow fix in `WasmPtr::new`).
+		let required_pages = (new_top as u64 + 65535) / 65536;
+		if required_pages > current_pages {
+			self.memory.grow(wasmi::memory_units::Pages((required_pages - current_pages) as usize))
+				.map_err(|_| Error::AllocationFailed)?;
+		}
+
+		self.dynamic_top = new_top;
+		Ok(previous_top)
+	}
+}
+
+/// Gas actually spent by a nested call/create given the amount handed to it
+/// and the amount it reported unused on return.
+fn gas_consumed(allotted: u64, returned_gas_left: U256) -> u64 {
+	allotted.saturating_sub(returned_gas_left.low_u64())
+}
+
+fn read_flag(buf: &[u8], cursor: &mut usize) -> Result<bool> {
+	if *cursor >= buf.len() {
+		return Err(Error::MemoryAccessViolation);
+	}
+	let flag = buf[*cursor] != 0;
+	*cursor += 1;
+	Ok(flag)
+}
+
+fn read_u32(buf: &[u8], cursor: &mut usize) -> Result<u32> {
+	if *cursor + 4 > buf.len() {
+		return Err(Error::MemoryAccessViolation);
+	}
+	let mut bytes = [0u8; 4];
+	bytes.copy_from_slice(&buf[*cursor..*cursor + 4]);
+	*cursor += 4;
+	Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_bytes<'a>(buf: &'a [u8], cursor: &mut usize, len: usize) -> Result<&'a [u8]> {
+	if *cursor + len > buf.len() {
+		return Err(Error::MemoryAccessViolation);
+	}
+	let slice = &buf[*cursor..*cursor + len];
+	*cursor += len;
+	Ok(slice)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{WasmPtr, WasmPtrError, gas_consumed};
+	use wasmi::MemoryInstance;
+	use wasmi::memory_units::Pages;
+	use ethereum_types::U256;
+
+	#[test]
+	fn wasm_ptr_rejects_zero_length() {
+		let memory = MemoryInstance::alloc(Pages(1), None).unwrap();
+		assert_eq!(WasmPtr::new(&memory, 0, 0).unwrap_err(), WasmPtrError::ZeroLength);
+	}
+
+	#[test]
+	fn wasm_ptr_rejects_offset_at_memory_size() {
+		let memory = MemoryInstance::alloc(Pages(1), None).unwrap();
+		let size = 1 * 64 * 1024;
+		assert_eq!(WasmPtr::new(&memory, size, 1).unwrap_err(), WasmPtrError::OutOfBoundsOffset);
+	}
+
+	#[test]
+	fn wasm_ptr_rejects_oversized_length() {
+		let memory = MemoryInstance::alloc(Pages(1), None).unwrap();
+		let size = 1 * 64 * 1024;
+		assert_eq!(WasmPtr::new(&memory, size - 1, 2).unwrap_err(), WasmPtrError::OversizedLength);
+	}
+
+	#[test]
+	fn wasm_ptr_accepts_region_fitting_exactly() {
+		let memory = MemoryInstance::alloc(Pages(1), None).unwrap();
+		let size = 1 * 64 * 1024;
+		let ptr = WasmPtr::new(&memory, size - 32, 32).unwrap();
+		assert_eq!(ptr.offset, size - 32);
+		assert_eq!(ptr.len, 32);
+	}
+
+	#[test]
+	fn gas_consumed_charges_only_the_difference() {
+		assert_eq!(gas_consumed(1000, U256::from(400)), 600);
+	}
+
+	#[test]
+	fn gas_consumed_charges_nothing_left_unused() {
+		assert_eq!(gas_consumed(1000, U256::from(1000)), 0);
+	}
+
+	#[test]
+	fn gas_consumed_saturates_when_callee_reports_more_than_allotted() {
+		assert_eq!(gas_consumed(1000, U256::from(1500)), 0);
+	}
 }
 
 mod ext_impl {
@@ -212,8 +729,21 @@ mod ext_impl {
 		) -> Result<Option<RuntimeValue>, Error> {
 			match index {
 				STORAGE_READ_FUNC => void!(self.storage_read(args)),
+				STORAGE_WRITE_FUNC => void!(self.storage_write(args)),
+				ELOG_FUNC => void!(self.elog(args)),
 				RET_FUNC => void!(self.ret(args)),
 				GAS_FUNC => void!(self.gas(args)),
+				ALLOC_FUNC => {
+					let offset = self.alloc(args)?;
+					Ok(Some(RuntimeValue::I32(offset as i32)))
+				},
+				BALANCE_FUNC => void!(self.balance(args)),
+				CCALL_FUNC => Ok(Some(self.ccall(args)?)),
+				DCALL_FUNC => Ok(Some(self.dcall(args)?)),
+				SCALL_FUNC => Ok(Some(self.scall(args)?)),
+				CREATE_FUNC => Ok(Some(self.create(args)?)),
+				SUICIDE_FUNC => void!(self.suicide(args)),
+				PANIC_FUNC => void!(self.panic(args)),
 				_ => panic!("env module doesn't provide function at index {}", index),
 			}
 		}